@@ -1,7 +1,25 @@
 
 use reqwest::RequestBuilder;use std::fmt::{self, Debug};
+use async_trait::async_trait;
+use crate::error::ApiClientError;
+
+#[async_trait]
 pub trait AuthStrategy: Send + Sync {
     fn apply_auth(&self, request: RequestBuilder) -> RequestBuilder;
+
+    /// Whether this strategy holds a token that can expire and needs
+    /// refreshing. `ApiClient` only calls `refresh_if_needed` when this is
+    /// `true`, so static strategies like `HeaderAuth` pay nothing extra.
+    fn is_refreshable(&self) -> bool {
+        false
+    }
+
+    /// Refreshes the underlying token if it is at or near expiry. Called by
+    /// `ApiClient` before `apply_auth` on every request when
+    /// `is_refreshable()` returns `true`.
+    async fn refresh_if_needed(&self) -> Result<(), ApiClientError> {
+        Ok(())
+    }
 }
 
 impl Debug for dyn AuthStrategy {
@@ -57,4 +75,268 @@ impl Debug for BearerAuth {
             .field("token", &"***") // Don't expose the actual token
             .finish()
     }
+}
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use rand::RngCore;
+use reqwest::Client as ReqwestClient;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// How far ahead of `expires_at` we proactively refresh, to avoid racing a
+/// token that expires mid-request.
+const EXPIRY_SKEW: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+struct TokenState {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_at: Option<Instant>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_in: Option<u64>,
+}
+
+/// The result of starting an authorization-code+PKCE flow: the URL to send
+/// the user to, and the verifier that must be kept around (e.g. in session
+/// state) to complete `OAuth2Auth::exchange_code`.
+#[derive(Debug, Clone)]
+pub struct PkceAuthorization {
+    pub url: String,
+    pub code_verifier: String,
+}
+
+/// OAuth2 authorization-code + PKCE strategy with automatic token refresh.
+///
+/// Use [`OAuth2Auth::authorize_url`] to start the flow and
+/// [`OAuth2Auth::exchange_code`] to turn the returned `code` into tokens, or
+/// construct directly with [`OAuth2Auth::new`] if tokens are already held
+/// (e.g. loaded from storage).
+pub struct OAuth2Auth {
+    http: ReqwestClient,
+    token_endpoint: String,
+    client_id: String,
+    client_secret: Option<String>,
+    state: Arc<RwLock<TokenState>>,
+    /// Serializes `refresh_if_needed` so two concurrent requests that both
+    /// observe an expiring token don't both redeem the same (possibly
+    /// single-use) refresh token.
+    refresh_lock: Mutex<()>,
+}
+
+impl OAuth2Auth {
+    pub fn new(
+        token_endpoint: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: Option<String>,
+        access_token: impl Into<String>,
+        refresh_token: Option<String>,
+        expires_at: Option<Instant>,
+    ) -> Self {
+        OAuth2Auth {
+            http: ReqwestClient::new(),
+            token_endpoint: token_endpoint.into(),
+            client_id: client_id.into(),
+            client_secret,
+            state: Arc::new(RwLock::new(TokenState {
+                access_token: access_token.into(),
+                refresh_token,
+                expires_at,
+            })),
+            refresh_lock: Mutex::new(()),
+        }
+    }
+
+    /// Builds the authorization URL for an authorization-code+PKCE flow,
+    /// generating a random `code_verifier` and deriving its S256
+    /// `code_challenge`.
+    pub fn authorize_url(
+        authorize_endpoint: &str,
+        client_id: &str,
+        redirect_uri: &str,
+        scope: &str,
+    ) -> Result<PkceAuthorization, ApiClientError> {
+        let code_verifier = generate_code_verifier();
+        let code_challenge = code_challenge_s256(&code_verifier);
+
+        let url = reqwest::Url::parse_with_params(
+            authorize_endpoint,
+            &[
+                ("response_type", "code"),
+                ("client_id", client_id),
+                ("redirect_uri", redirect_uri),
+                ("scope", scope),
+                ("code_challenge", code_challenge.as_str()),
+                ("code_challenge_method", "S256"),
+            ],
+        )
+        .map_err(|e| ApiClientError::Unexpected(e.to_string()))?;
+
+        Ok(PkceAuthorization {
+            url: url.to_string(),
+            code_verifier,
+        })
+    }
+
+    /// Exchanges an authorization `code` (and the `code_verifier` returned
+    /// alongside the authorize URL) for an access/refresh token pair.
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+        redirect_uri: &str,
+        code_verifier: &str,
+    ) -> Result<(), ApiClientError> {
+        let mut params = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+            ("client_id", self.client_id.as_str()),
+            ("code_verifier", code_verifier),
+        ];
+        if let Some(secret) = &self.client_secret {
+            params.push(("client_secret", secret.as_str()));
+        }
+
+        let token = self.request_token(&params).await?;
+        self.store_token(token);
+        Ok(())
+    }
+
+    async fn request_token(&self, params: &[(&str, &str)]) -> Result<TokenResponse, ApiClientError> {
+        let response = self
+            .http
+            .post(&self.token_endpoint)
+            .form(params)
+            .send()
+            .await
+            .map_err(ApiClientError::Network)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ApiClientError::ApiError { status, body });
+        }
+
+        response
+            .json::<TokenResponse>()
+            .await
+            .map_err(ApiClientError::Network)
+    }
+
+    fn store_token(&self, token: TokenResponse) {
+        let expires_at = token
+            .expires_in
+            .map(|secs| Instant::now() + Duration::from_secs(secs));
+
+        let mut state = self.state.write().unwrap();
+        state.access_token = token.access_token;
+        if let Some(refresh_token) = token.refresh_token {
+            state.refresh_token = Some(refresh_token);
+        }
+        state.expires_at = expires_at;
+    }
+
+    /// Whether the current token is at or near `expires_at`, per `EXPIRY_SKEW`.
+    fn needs_refresh(&self) -> bool {
+        match self.state.read().unwrap().expires_at {
+            Some(expires_at) => Instant::now() + EXPIRY_SKEW >= expires_at,
+            None => false,
+        }
+    }
+}
+
+#[async_trait]
+impl AuthStrategy for OAuth2Auth {
+    fn apply_auth(&self, request: RequestBuilder) -> RequestBuilder {
+        // A synchronous read of the last-known-good token: cheap, never
+        // blocks on a refresh, and never sends a request with no
+        // Authorization header.
+        let access_token = self.state.read().unwrap().access_token.clone();
+        request.bearer_auth(access_token)
+    }
+
+    fn is_refreshable(&self) -> bool {
+        true
+    }
+
+    async fn refresh_if_needed(&self) -> Result<(), ApiClientError> {
+        if !self.needs_refresh() {
+            return Ok(());
+        }
+
+        // Single-flight: only one concurrent caller redeems the refresh
+        // token. Others block here, then see the already-refreshed token
+        // and return without a second (possibly rejected, if the provider
+        // rotates refresh tokens) request.
+        let _guard = self.refresh_lock.lock().await;
+
+        if !self.needs_refresh() {
+            return Ok(());
+        }
+
+        let Some(refresh_token) = self.state.read().unwrap().refresh_token.clone() else {
+            return Ok(());
+        };
+
+        let mut params = vec![
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token.as_str()),
+            ("client_id", self.client_id.as_str()),
+        ];
+        if let Some(secret) = &self.client_secret {
+            params.push(("client_secret", secret.as_str()));
+        }
+
+        let token = self.request_token(&params).await?;
+        self.store_token(token);
+        Ok(())
+    }
+}
+
+impl Debug for OAuth2Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuth2Auth")
+            .field("token_endpoint", &self.token_endpoint)
+            .field("client_id", &self.client_id)
+            .field("access_token", &"***")
+            .field("refresh_token", &"***")
+            .finish()
+    }
+}
+
+fn generate_code_verifier() -> String {
+    let mut bytes = [0u8; 64];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Derives a PKCE `code_challenge` from `code_verifier` per RFC 7636
+/// `S256`: base64url-nopad of the verifier's SHA-256 digest.
+fn code_challenge_s256(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RFC 7636 Appendix B test vector.
+    #[test]
+    fn code_challenge_s256_matches_rfc7636_test_vector() {
+        let code_verifier = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+        let code_challenge = code_challenge_s256(code_verifier);
+        assert_eq!(code_challenge, "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM");
+    }
+
+    #[test]
+    fn generate_code_verifier_produces_unique_values() {
+        assert_ne!(generate_code_verifier(), generate_code_verifier());
+    }
 }
\ No newline at end of file
@@ -1,69 +1,169 @@
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use reqwest::{Client as ReqwestClient, RequestBuilder, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use serde_json::Value;
 use crate::error::ApiClientError;
 use crate::auth::AuthStrategy;
+use crate::ratelimit::{parse_retry_after, route_key, RateLimiter};
+use crate::multipart::MultipartRequest;
 use tracing::{info, debug, error, instrument};
 use std::sync::Arc;
 use tokio::time::{sleep, Duration};
+use rand::Rng;
 
-pub type    ApiResult<T> = Result<T, ApiClientError>;
+pub type    ApiResult<T, E = crate::error::NoStructuredError> = Result<T, ApiClientError<E>>;
+
+/// Maximum number of 429 retries when the caller doesn't configure one.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// How long `execute_request` waits between retries when a 429 response
+/// carries no `Retry-After` header.
+#[derive(Debug, Clone)]
+pub enum Backoff {
+    /// Always sleep the same duration.
+    Fixed(Duration),
+    /// Exponential backoff with full jitter: `sleep = rand(0, min(cap, base * 2^attempt))`.
+    ExponentialJitter { base: Duration, cap: Duration },
+}
+
+impl Backoff {
+    fn duration_for(&self, attempt: u32) -> Duration {
+        match self {
+            Backoff::Fixed(duration) => *duration,
+            Backoff::ExponentialJitter { base, cap } => {
+                let factor = 2u32.checked_pow(attempt).unwrap_or(u32::MAX);
+                let bound = base.saturating_mul(factor).min(*cap);
+                let millis = bound.as_millis() as u64;
+                if millis == 0 {
+                    Duration::ZERO
+                } else {
+                    Duration::from_millis(rand::thread_rng().gen_range(0..=millis))
+                }
+            }
+        }
+    }
+}
+
+/// Retry behavior for rate-limited (429) requests: how many times to retry
+/// and how long to sleep between attempts absent a `Retry-After` header.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub backoff: Backoff,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: DEFAULT_MAX_RETRIES,
+            backoff: Backoff::Fixed(Duration::from_secs(2)),
+        }
+    }
+}
+
+/// A request payload for `put`/`patch`/`delete`, covering the shapes real
+/// APIs expect beyond the JSON `post` assumes.
+#[derive(Debug, Clone)]
+pub enum RequestBody {
+    Json(Value),
+    Form(Vec<(String, String)>),
+    Raw { bytes: Vec<u8>, content_type: String },
+}
 
 #[derive(Debug, Clone)]
 pub struct ApiClient {
     base_url: String,
     client: ReqwestClient,
     auth_strategy: Option<Arc<dyn AuthStrategy>>, // Using Arc to allow cloning
+    rate_limiter: RateLimiter,
+    retry_policy: RetryPolicy,
 }
 
 impl ApiClient {
     pub fn new(base_url: &str, auth_strategy: Option<Arc<dyn AuthStrategy>>) -> Self {
-        ApiClient {
-            base_url: base_url.trim_end_matches('/').to_string(),
-            client: ReqwestClient::new(),
-            auth_strategy,
-        }
+        let mut builder = ApiClientBuilder::new(base_url);
+        builder.auth_strategy = auth_strategy;
+        builder
+            .build()
+            .expect("default ApiClient configuration should always build")
+    }
+
+    /// Entry point for configuring timeouts, retry policy, and default
+    /// headers beyond what `ApiClient::new` assumes.
+    pub fn builder(base_url: &str) -> ApiClientBuilder {
+        ApiClientBuilder::new(base_url)
     }
 
-    fn apply_auth(&self, request: RequestBuilder) -> RequestBuilder {
+    /// Refreshes the configured auth strategy (if it reports itself as
+    /// refreshable) and then applies it to `request`.
+    async fn apply_auth(&self, request: RequestBuilder) -> RequestBuilder {
         if let Some(auth) = &self.auth_strategy {
+            if auth.is_refreshable() {
+                if let Err(e) = auth.refresh_if_needed().await {
+                    error!("Failed to refresh auth token: {:?}", e);
+                }
+            }
             auth.apply_auth(request)
         } else {
             request
         }
     }
 
-    #[instrument(skip(self))]
     pub async fn get<T>(&self, endpoint: &str, params: Option<&[(String, String)]>) -> ApiResult<T>
     where
         T: DeserializeOwned,
+    {
+        self.get_as(endpoint, params).await
+    }
+
+    /// Like `get`, but deserializes a non-2xx body into `E` (e.g.
+    /// `serde_json::Value`, or a schema-specific error struct) instead of
+    /// always falling back to `ApiClientError::ApiError`, reporting a match
+    /// via `ApiClientError::Structured`.
+    #[instrument(skip(self))]
+    pub async fn get_as<T, E>(&self, endpoint: &str, params: Option<&[(String, String)]>) -> ApiResult<T, E>
+    where
+        T: DeserializeOwned,
+        E: DeserializeOwned,
     {
         let url = format!("{}/{}", self.base_url, endpoint.trim_start_matches('/'));
         info!("Sending GET request to URL: {}", url);
 
         let mut request = self.client.get(&url);
-        request = self.apply_auth(request);
+        request = self.apply_auth(request).await;
 
         if let Some(params) = params {
             request = request.query(params);
             debug!("Added query parameters: {:?}", params);
         }
 
-        self.execute_request(request).await
+        self.execute_request(request, &route_key(endpoint)).await
     }
 
-    #[instrument(skip(self, body))]
     pub async fn post<T, B>(&self, endpoint: &str, body: Option<&B>) -> ApiResult<T>
     where
         T: DeserializeOwned,
         B: Serialize,
+    {
+        self.post_as(endpoint, body).await
+    }
+
+    /// Like `post`, but deserializes a non-2xx body into `E` instead of
+    /// always falling back to `ApiClientError::ApiError` (see
+    /// `ApiClientError::Structured`).
+    #[instrument(skip(self, body))]
+    pub async fn post_as<T, B, E>(&self, endpoint: &str, body: Option<&B>) -> ApiResult<T, E>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+        E: DeserializeOwned,
     {
         let url = format!("{}/{}", self.base_url, endpoint.trim_start_matches('/'));
         info!("Sending POST request to URL: {}", url);
 
         let mut request = self.client.post(&url);
-        request = self.apply_auth(request);
+        request = self.apply_auth(request).await;
 
         if let Some(body) = body {
             request = request.json(body);
@@ -79,64 +179,301 @@ impl ApiClient {
             }
         }
 
-        debug!("Sending request {:?}", request);
+        self.execute_request(request, &route_key(endpoint)).await
+    }
+
+    pub async fn put<T>(&self, endpoint: &str, body: Option<RequestBody>) -> ApiResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.put_as(endpoint, body).await
+    }
+
+    /// Like `put`, but deserializes a non-2xx body into `E` instead of
+    /// always falling back to `ApiClientError::ApiError` (see
+    /// `ApiClientError::Structured`).
+    pub async fn put_as<T, E>(&self, endpoint: &str, body: Option<RequestBody>) -> ApiResult<T, E>
+    where
+        T: DeserializeOwned,
+        E: DeserializeOwned,
+    {
+        self.request_with_body(reqwest::Method::PUT, endpoint, body).await
+    }
+
+    pub async fn patch<T>(&self, endpoint: &str, body: Option<RequestBody>) -> ApiResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.patch_as(endpoint, body).await
+    }
+
+    /// Like `patch`, but deserializes a non-2xx body into `E` instead of
+    /// always falling back to `ApiClientError::ApiError` (see
+    /// `ApiClientError::Structured`).
+    pub async fn patch_as<T, E>(&self, endpoint: &str, body: Option<RequestBody>) -> ApiResult<T, E>
+    where
+        T: DeserializeOwned,
+        E: DeserializeOwned,
+    {
+        self.request_with_body(reqwest::Method::PATCH, endpoint, body).await
+    }
+
+    pub async fn delete<T>(&self, endpoint: &str, body: Option<RequestBody>) -> ApiResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.delete_as(endpoint, body).await
+    }
+
+    /// Like `delete`, but deserializes a non-2xx body into `E` instead of
+    /// always falling back to `ApiClientError::ApiError` (see
+    /// `ApiClientError::Structured`).
+    pub async fn delete_as<T, E>(&self, endpoint: &str, body: Option<RequestBody>) -> ApiResult<T, E>
+    where
+        T: DeserializeOwned,
+        E: DeserializeOwned,
+    {
+        self.request_with_body(reqwest::Method::DELETE, endpoint, body).await
+    }
+
+    /// Shared implementation for `put`/`patch`/`delete`: builds a request
+    /// for `method` carrying `body`, applies auth, and sends it through the
+    /// same retry/rate-limit path as `get`/`post`. Generic over the
+    /// structured error type `E` (see `ApiClientError::Structured`);
+    /// `put`/`patch`/`delete` pin it to the default `NoStructuredError`,
+    /// while `put_as`/`patch_as`/`delete_as` pass the caller's `E` through.
+    #[instrument(skip(self, body))]
+    async fn request_with_body<T, E>(
+        &self,
+        method: reqwest::Method,
+        endpoint: &str,
+        body: Option<RequestBody>,
+    ) -> ApiResult<T, E>
+    where
+        T: DeserializeOwned,
+        E: DeserializeOwned,
+    {
+        let url = format!("{}/{}", self.base_url, endpoint.trim_start_matches('/'));
+        info!("Sending {} request to URL: {}", method, url);
+
+        let mut request = self.client.request(method, &url);
+        request = self.apply_auth(request).await;
+        request = match body {
+            Some(RequestBody::Json(value)) => request.json(&value),
+            Some(RequestBody::Form(pairs)) => request.form(&pairs),
+            Some(RequestBody::Raw { bytes, content_type }) => {
+                request.header(reqwest::header::CONTENT_TYPE, content_type).body(bytes)
+            }
+            None => request,
+        };
+
+        self.execute_request(request, &route_key(endpoint)).await
+    }
+
+    pub async fn post_multipart<T>(&self, endpoint: &str, form: MultipartRequest) -> ApiResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.post_multipart_as(endpoint, form).await
+    }
+
+    /// Like `post_multipart`, but deserializes a non-2xx body into `E`
+    /// instead of always falling back to `ApiClientError::ApiError` (see
+    /// `ApiClientError::Structured`).
+    pub async fn post_multipart_as<T, E>(&self, endpoint: &str, form: MultipartRequest) -> ApiResult<T, E>
+    where
+        T: DeserializeOwned,
+        E: DeserializeOwned,
+    {
+        self.send_multipart(reqwest::Method::POST, endpoint, form).await
+    }
+
+    pub async fn put_multipart<T>(&self, endpoint: &str, form: MultipartRequest) -> ApiResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.put_multipart_as(endpoint, form).await
+    }
+
+    /// Like `put_multipart`, but deserializes a non-2xx body into `E`
+    /// instead of always falling back to `ApiClientError::ApiError` (see
+    /// `ApiClientError::Structured`).
+    pub async fn put_multipart_as<T, E>(&self, endpoint: &str, form: MultipartRequest) -> ApiResult<T, E>
+    where
+        T: DeserializeOwned,
+        E: DeserializeOwned,
+    {
+        self.send_multipart(reqwest::Method::PUT, endpoint, form).await
+    }
+
+    /// Shared implementation for `post_multipart`/`put_multipart`. Streaming
+    /// file parts can't be cloned for a retry, so unlike `execute_request`
+    /// this sends once and relies on `handle_response` for consistent
+    /// status/rate-limit (and structured-error, via `E`) handling.
+    #[instrument(skip(self, form))]
+    async fn send_multipart<T, E>(
+        &self,
+        method: reqwest::Method,
+        endpoint: &str,
+        form: MultipartRequest,
+    ) -> ApiResult<T, E>
+    where
+        T: DeserializeOwned,
+        E: DeserializeOwned,
+    {
+        let url = format!("{}/{}", self.base_url, endpoint.trim_start_matches('/'));
+        info!("Sending {} multipart request to URL: {}", method, url);
+
+        let route = route_key(endpoint);
+        self.rate_limiter.wait_for_capacity(&route).await;
+
+        let mut request = self.client.request(method, &url).multipart(form.into_form());
+        request = self.apply_auth(request).await;
 
         let response = request.send().await.map_err(|e| {
-            error!("Network error while sending POST request to {}: {:?}", url, e);
+            error!("Network error while sending multipart request to {}: {:?}", url, e);
             ApiClientError::Network(e)
         })?;
 
-        self.handle_response(response).await
+        self.rate_limiter.observe(&route, response.headers()).await;
+        self.handle_response(response).await.map(|(value, _headers)| value)
     }
 
-    #[instrument(skip(self))]
-    async fn execute_request<T>(&self, request: RequestBuilder) -> ApiResult<T>
+    /// Sends `request`, honoring any rate-limit bucket tracked for `route`
+    /// and retrying on 429 using the `Retry-After` header (falling back to
+    /// `self.retry_policy.backoff`), up to `self.retry_policy.max_retries`
+    /// times.
+    #[instrument(skip(self, request))]
+    async fn execute_request<T, E>(&self, request: RequestBuilder, route: &str) -> ApiResult<T, E>
+    where
+        T: DeserializeOwned,
+        E: DeserializeOwned,
+    {
+        self.execute_request_with_headers(request, route)
+            .await
+            .map(|(value, _headers)| value)
+    }
+
+    /// Like `execute_request`, but keeps the response headers around (e.g.
+    /// for `get_paginated` to read the `Link` header).
+    async fn execute_request_with_headers<T, E>(
+        &self,
+        request: RequestBuilder,
+        route: &str,
+    ) -> ApiResult<(T, reqwest::header::HeaderMap), E>
     where
         T: DeserializeOwned,
+        E: DeserializeOwned,
     {
-        let mut retries = 3;
+        let mut attempt = 0;
+
+        loop {
+            self.rate_limiter.wait_for_capacity(route).await;
 
-        while retries > 0 {
             let response = request.try_clone().unwrap().send().await.map_err(|e| {
                 error!("Network error while sending request: {:?}", e);
                 ApiClientError::Network(e)
             })?;
 
-            match self.handle_response(response).await {
-                Ok(result) => return Ok(result),
-                Err(ApiClientError::RateLimit(ref message)) => {
-                    error!("Rate limit exceeded: {}", message);
-                    retries -= 1;
-                    sleep(Duration::from_secs(2)).await;
-                }
-                Err(e) => {
-                    return Err(e);
-                }
+            self.rate_limiter.observe(route, response.headers()).await;
+
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return self.handle_response(response).await;
+            }
+
+            attempt += 1;
+            if attempt > self.retry_policy.max_retries {
+                return Err(ApiClientError::MaxRetriesReached);
             }
-        }
 
-        Err(ApiClientError::MaxRetriesReached)
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_retry_after)
+                .unwrap_or_else(|| self.retry_policy.backoff.duration_for(attempt));
+
+            let body = response.text().await.unwrap_or_default();
+            error!(
+                "Rate limited on route {} (attempt {}/{}): {}; retrying in {:?}",
+                route, attempt, self.retry_policy.max_retries, body, retry_after
+            );
+            sleep(retry_after).await;
+        }
     }
 
+    /// Parses a response body on success, or, for a non-2xx status,
+    /// attempts to deserialize it into the structured error type `E`
+    /// (reported via `ApiClientError::Structured`), falling back to the raw
+    /// `ApiClientError::ApiError { status, body }` when that fails. Returns
+    /// the response headers alongside the body so callers like
+    /// `get_paginated` can inspect `Link` without a second round-trip.
     #[instrument(skip(self))]
-    async fn handle_response<T>(&self, response: Response) -> ApiResult<T>
+    async fn handle_response<T, E>(&self, response: Response) -> ApiResult<(T, reqwest::header::HeaderMap), E>
     where
         T: DeserializeOwned,
+        E: DeserializeOwned,
     {
         let status = response.status();
+        let headers = response.headers().clone();
         let body = response.text().await.unwrap_or_else(|_| "Failed to read response body".to_string());
 
         if status.is_success() {
             // First, try to deserialize the response
-            serde_json::from_str::<T>(&body).map_err(|err| {
-                error!("Failed to parse JSON response: {:?}", err);
-                ApiClientError::JsonParse(err)
-            })
+            serde_json::from_str::<T>(&body)
+                .map(|value| (value, headers))
+                .map_err(|err| {
+                    error!("Failed to parse JSON response: {:?}", err);
+                    ApiClientError::JsonParse(err)
+                })
         } else if status == StatusCode::TOO_MANY_REQUESTS {
             Err(ApiClientError::RateLimit(body))
         } else {
-            Err(ApiClientError::ApiError { status, body })
+            let deserializer = &mut serde_json::Deserializer::from_str(&body);
+            match serde_path_to_error::deserialize::<_, E>(deserializer) {
+                Ok(error) => Err(ApiClientError::Structured { status, error }),
+                Err(err) => {
+                    debug!("Structured error body at {} did not match error schema: {}", err.path(), err);
+                    Err(ApiClientError::ApiError { status, body })
+                }
+            }
+        }
+    }
+
+    /// Fetches one page as raw `Value` plus response headers, for
+    /// `get_paginated` to inspect before deserializing into the caller's
+    /// item type.
+    pub(crate) async fn get_page(
+        &self,
+        endpoint: &str,
+        params: Option<&[(String, String)]>,
+    ) -> ApiResult<(Value, reqwest::header::HeaderMap)> {
+        let url = format!("{}/{}", self.base_url, endpoint.trim_start_matches('/'));
+        info!("Fetching page from URL: {}", url);
+
+        let mut request = self.client.get(&url);
+        request = self.apply_auth(request).await;
+
+        if let Some(params) = params {
+            request = request.query(params);
         }
+
+        self.execute_request_with_headers(request, &route_key(endpoint)).await
+    }
+
+    /// Like `get_page`, but for a fully-qualified `next` URL taken from a
+    /// `Link` header rather than `{base_url}/{endpoint}`.
+    pub(crate) async fn get_page_absolute(
+        &self,
+        url: &str,
+        route: &str,
+    ) -> ApiResult<(Value, reqwest::header::HeaderMap)> {
+        info!("Fetching next page from URL: {}", url);
+
+        let mut request = self.client.get(url);
+        request = self.apply_auth(request).await;
+
+        self.execute_request_with_headers(request, route).await
     }
 
     pub fn serialize_params<B>(&self, params: Option<&B>) -> ApiResult<Option<Vec<(String, String)>>>
@@ -150,12 +487,15 @@ impl ApiClient {
         
             if let Value::Object(map) = value {
                 for (key, value) in map.iter() {
+                    if value.is_null() {
+                        continue;
+                    }
                     let key_str = key.clone();
                     let value_str = match value {
                         Value::String(s) => s.clone(),
                         Value::Bool(b) => b.to_string(),
                         Value::Number(n) => n.to_string(),
-                        _ => continue,
+                        other => other.to_string(),
                     };
                     pairs.push((key_str, value_str));
                 }
@@ -185,3 +525,157 @@ impl ApiClient {
         })
     }
 }
+
+/// Builds an `ApiClient` with request timeouts, a rate-limit `RetryPolicy`,
+/// and default headers (e.g. a `User-Agent` or an API-version header)
+/// applied to every request. `ApiClient::new` is a shortcut for the
+/// defaults this produces.
+pub struct ApiClientBuilder {
+    base_url: String,
+    auth_strategy: Option<Arc<dyn AuthStrategy>>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+    default_headers: HeaderMap,
+}
+
+impl ApiClientBuilder {
+    pub fn new(base_url: &str) -> Self {
+        ApiClientBuilder {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            auth_strategy: None,
+            connect_timeout: None,
+            timeout: None,
+            retry_policy: RetryPolicy::default(),
+            default_headers: HeaderMap::new(),
+        }
+    }
+
+    pub fn auth_strategy(mut self, auth_strategy: Arc<dyn AuthStrategy>) -> Self {
+        self.auth_strategy = Some(auth_strategy);
+        self
+    }
+
+    /// Caps the whole request (connect + send + receive); unset means
+    /// reqwest's own (no) default applies.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Adds a header sent with every request (e.g. `User-Agent` or an
+    /// API-version header like `X-KANIDM-VERSION`).
+    pub fn default_header(mut self, name: &str, value: &str) -> ApiResult<Self> {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|e| ApiClientError::Unexpected(e.to_string()))?;
+        let value = HeaderValue::from_str(value)
+            .map_err(|e| ApiClientError::Unexpected(e.to_string()))?;
+        self.default_headers.insert(name, value);
+        Ok(self)
+    }
+
+    pub fn build(self) -> ApiResult<ApiClient> {
+        let mut client_builder = ReqwestClient::builder().default_headers(self.default_headers);
+
+        if let Some(timeout) = self.timeout {
+            client_builder = client_builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            client_builder = client_builder.connect_timeout(connect_timeout);
+        }
+
+        let client = client_builder.build().map_err(ApiClientError::Network)?;
+
+        Ok(ApiClient {
+            base_url: self.base_url,
+            client,
+            auth_strategy: self.auth_strategy,
+            rate_limiter: RateLimiter::new(),
+            retry_policy: self.retry_policy,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_fixed_always_returns_the_same_duration() {
+        let backoff = Backoff::Fixed(Duration::from_secs(2));
+        assert_eq!(backoff.duration_for(0), Duration::from_secs(2));
+        assert_eq!(backoff.duration_for(5), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn backoff_exponential_jitter_stays_within_base_times_two_to_the_attempt() {
+        let backoff = Backoff::ExponentialJitter {
+            base: Duration::from_millis(100),
+            cap: Duration::from_secs(60),
+        };
+
+        for attempt in 0..5 {
+            let bound = Duration::from_millis(100 * 2u64.pow(attempt));
+            for _ in 0..20 {
+                let sleep = backoff.duration_for(attempt);
+                assert!(sleep <= bound, "attempt {attempt}: {sleep:?} > {bound:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn backoff_exponential_jitter_clamps_to_cap() {
+        let backoff = Backoff::ExponentialJitter {
+            base: Duration::from_secs(1),
+            cap: Duration::from_secs(5),
+        };
+
+        // 2^20 seconds of base easily exceeds the cap, so every sample must
+        // be clamped to it.
+        for _ in 0..20 {
+            assert!(backoff.duration_for(20) <= Duration::from_secs(5));
+        }
+    }
+
+    #[test]
+    fn backoff_exponential_jitter_handles_a_zero_base() {
+        let backoff = Backoff::ExponentialJitter {
+            base: Duration::from_secs(0),
+            cap: Duration::from_secs(5),
+        };
+
+        assert_eq!(backoff.duration_for(3), Duration::ZERO);
+    }
+
+    #[test]
+    fn request_body_variants_carry_their_payload() {
+        match RequestBody::Json(serde_json::json!({"a": 1})) {
+            RequestBody::Json(value) => assert_eq!(value, serde_json::json!({"a": 1})),
+            other => panic!("expected RequestBody::Json, got {other:?}"),
+        }
+
+        match RequestBody::Form(vec![("a".to_string(), "1".to_string())]) {
+            RequestBody::Form(pairs) => assert_eq!(pairs, vec![("a".to_string(), "1".to_string())]),
+            other => panic!("expected RequestBody::Form, got {other:?}"),
+        }
+
+        let raw = RequestBody::Raw { bytes: vec![1, 2, 3], content_type: "application/octet-stream".to_string() };
+        match raw {
+            RequestBody::Raw { bytes, content_type } => {
+                assert_eq!(bytes, vec![1, 2, 3]);
+                assert_eq!(content_type, "application/octet-stream");
+            }
+            other => panic!("expected RequestBody::Raw, got {other:?}"),
+        }
+    }
+}
@@ -1,8 +1,15 @@
 use reqwest::StatusCode;
 use thiserror::Error;
 
+/// `E` is the type a non-2xx response body is deserialized into. It defaults
+/// to [`NoStructuredError`], which never deserializes successfully, so
+/// `get`/`post`/`put`/`patch`/`delete` keep returning the original
+/// `ApiClientError::ApiError { status, body }` on failure. Callers that want
+/// a typed error body (e.g. `serde_json::Value`, or a schema matching the
+/// API's documented error shape) opt in with `get_as`/`post_as`/etc., which
+/// are generic over `E` and report a match via `ApiClientError::Structured`.
 #[derive(Debug, Error)]
-pub enum ApiClientError {
+pub enum ApiClientError<E = NoStructuredError> {
     #[error("Network error: {0}")]
     Network(#[from] reqwest::Error),
 
@@ -24,9 +31,65 @@ pub enum ApiClientError {
         body: String,
     },
 
+    /// A non-2xx response whose body was successfully deserialized into
+    /// `E`. Falls back to `ApiError` when deserialization fails.
+    #[error("API returned a structured error: status {status}")]
+    Structured {
+        status: StatusCode,
+        error: E,
+    },
+
     #[error("Unexpected error: {0}")]
     Unexpected(String),
 
     #[error("Maximum retries reached")]
     MaxRetriesReached,
 }
+
+/// The default `ApiClientError` structured-error type: it always fails to
+/// deserialize, so callers that never opted into a typed error body (via
+/// `get_as`/`post_as`/etc.) can't silently get `ApiClientError::Structured`
+/// instead of the raw `ApiError { status, body }` they're used to matching
+/// on. Use `serde_json::Value`, or a schema-specific struct, via the `_as`
+/// methods to opt in.
+#[derive(Debug)]
+pub struct NoStructuredError;
+
+impl<'de> serde::Deserialize<'de> for NoStructuredError {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Err(serde::de::Error::custom(
+            "no structured error type configured; use a `_as` method to opt in",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_structured_error_fails_on_any_body() {
+        assert!(serde_json::from_str::<NoStructuredError>("{}").is_err());
+        assert!(serde_json::from_str::<NoStructuredError>(r#"{"code": "bad_request"}"#).is_err());
+        assert!(serde_json::from_str::<NoStructuredError>("null").is_err());
+    }
+
+    /// Mirrors the fallback `handle_response` uses: deserializing the body
+    /// into `E` via `serde_path_to_error`. With the default `E =
+    /// NoStructuredError` this always fails (so callers get `ApiError`);
+    /// opting into `E = serde_json::Value` (what `_as` methods allow)
+    /// always succeeds for valid JSON (so callers get `Structured`).
+    #[test]
+    fn structured_vs_api_error_fallback_depends_on_e() {
+        let body = r#"{"message": "invalid field"}"#;
+
+        let default_deserializer = &mut serde_json::Deserializer::from_str(body);
+        assert!(serde_path_to_error::deserialize::<_, NoStructuredError>(default_deserializer).is_err());
+
+        let opted_in_deserializer = &mut serde_json::Deserializer::from_str(body);
+        assert!(serde_path_to_error::deserialize::<_, serde_json::Value>(opted_in_deserializer).is_ok());
+    }
+}
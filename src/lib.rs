@@ -3,8 +3,13 @@ pub mod error;
 pub mod auth;
 pub mod query;
 pub mod macros;
+pub mod ratelimit;
+pub mod multipart;
+pub mod pagination;
 
-pub use client::{ApiClient, ApiResult};
-pub use error::ApiClientError;
-pub use auth::{AuthStrategy, HeaderAuth, BearerAuth};
+pub use client::{ApiClient, ApiClientBuilder, ApiResult, Backoff, RequestBody, RetryPolicy};
+pub use error::{ApiClientError, NoStructuredError};
+pub use auth::{AuthStrategy, HeaderAuth, BearerAuth, OAuth2Auth, PkceAuthorization};
+pub use multipart::{FilePart, MultipartRequest};
+pub use pagination::{CursorField, PageOpts};
 
@@ -38,12 +38,245 @@ macro_rules! create_api_submodule {
                     .post(&full_endpoint, body)
                     .await
             }
+
+            pub async fn put<T, B>(&self, endpoint: &str, body: Option<&B>) -> apiclient_rs::ApiResult<T>
+            where
+                T: serde::de::DeserializeOwned,
+                B: serde::Serialize,
+            {
+                let full_endpoint = format!("{}{}", self.base_path, endpoint);
+                let request_body = Self::json_body(body)?;
+                self.client.as_ref().put(&full_endpoint, request_body).await
+            }
+
+            pub async fn patch<T, B>(&self, endpoint: &str, body: Option<&B>) -> apiclient_rs::ApiResult<T>
+            where
+                T: serde::de::DeserializeOwned,
+                B: serde::Serialize,
+            {
+                let full_endpoint = format!("{}{}", self.base_path, endpoint);
+                let request_body = Self::json_body(body)?;
+                self.client.as_ref().patch(&full_endpoint, request_body).await
+            }
+
+            pub async fn delete<T, B>(&self, endpoint: &str, body: Option<&B>) -> apiclient_rs::ApiResult<T>
+            where
+                T: serde::de::DeserializeOwned,
+                B: serde::Serialize,
+            {
+                let full_endpoint = format!("{}{}", self.base_path, endpoint);
+                let request_body = Self::json_body(body)?;
+                self.client.as_ref().delete(&full_endpoint, request_body).await
+            }
+
+            fn json_body<B>(body: Option<&B>) -> apiclient_rs::ApiResult<Option<apiclient_rs::RequestBody>>
+            where
+                B: serde::Serialize,
+            {
+                body.map(serde_json::to_value)
+                    .transpose()
+                    .map(|value| value.map(apiclient_rs::RequestBody::Json))
+                    .map_err(|e| apiclient_rs::ApiClientError::DeserializeError(e.to_string()))
+            }
+
+            pub async fn put_form<T, B>(&self, endpoint: &str, body: Option<&B>) -> apiclient_rs::ApiResult<T>
+            where
+                T: serde::de::DeserializeOwned,
+                B: serde::Serialize,
+            {
+                let full_endpoint = format!("{}{}", self.base_path, endpoint);
+                let request_body = self.build_form_body(body)?;
+                self.client.as_ref().put(&full_endpoint, request_body).await
+            }
+
+            pub async fn patch_form<T, B>(&self, endpoint: &str, body: Option<&B>) -> apiclient_rs::ApiResult<T>
+            where
+                T: serde::de::DeserializeOwned,
+                B: serde::Serialize,
+            {
+                let full_endpoint = format!("{}{}", self.base_path, endpoint);
+                let request_body = self.build_form_body(body)?;
+                self.client.as_ref().patch(&full_endpoint, request_body).await
+            }
+
+            pub async fn delete_form<T, B>(&self, endpoint: &str, body: Option<&B>) -> apiclient_rs::ApiResult<T>
+            where
+                T: serde::de::DeserializeOwned,
+                B: serde::Serialize,
+            {
+                let full_endpoint = format!("{}{}", self.base_path, endpoint);
+                let request_body = self.build_form_body(body)?;
+                self.client.as_ref().delete(&full_endpoint, request_body).await
+            }
+
+            /// Flattens `body` into `application/x-www-form-urlencoded`
+            /// pairs the same way `ApiClient::serialize_params` does for
+            /// query params.
+            fn build_form_body<B>(&self, body: Option<&B>) -> apiclient_rs::ApiResult<Option<apiclient_rs::RequestBody>>
+            where
+                B: serde::Serialize,
+            {
+                Ok(self
+                    .client
+                    .as_ref()
+                    .serialize_params(body)?
+                    .map(apiclient_rs::RequestBody::Form))
+            }
+
+            pub async fn post_multipart<T>(&self, endpoint: &str, form: apiclient_rs::MultipartRequest) -> apiclient_rs::ApiResult<T>
+            where
+                T: serde::de::DeserializeOwned,
+            {
+                let full_endpoint = format!("{}{}", self.base_path, endpoint);
+                self.client.as_ref().post_multipart(&full_endpoint, form).await
+            }
+
+            pub async fn put_multipart<T>(&self, endpoint: &str, form: apiclient_rs::MultipartRequest) -> apiclient_rs::ApiResult<T>
+            where
+                T: serde::de::DeserializeOwned,
+            {
+                let full_endpoint = format!("{}{}", self.base_path, endpoint);
+                self.client.as_ref().put_multipart(&full_endpoint, form).await
+            }
         }
     };
 }
 
 #[macro_export]
 macro_rules! define_api_endpoint {
+    // Multipart body (file upload) endpoints
+    (
+        $(#[$meta:meta])*
+        impl $impl_target:ty;
+        fn $fn_name:ident(
+            &self $(, $path_param:ident : $path_type:ty)* $(,)?
+            $(; required_params: {$($req_param:ident : $req_type:ty),* $(,)?})?
+            $(; optional_params: {$($opt_param:ident : $opt_type:ty),* $(,)?})?
+        ) -> $response_type:ident;
+        method: multipart($verb:ident);
+        endpoint: $endpoint_fmt:expr;
+        response_fields: {
+            $(
+                $(#[$field_meta:meta])*
+                $resp_field:ident : $resp_type:ty
+            ),* $(,)?
+        }
+    ) => {
+        paste::paste! {
+            // Define the parameter struct; its fields become multipart text fields
+            #[derive(Debug, Clone, serde::Serialize, derive_builder::Builder)]
+            #[builder(public)]
+            #[serde(rename_all = "camelCase")]
+            pub struct [<$fn_name:camel Params>] {
+                $(
+                    $(
+                        #[builder(setter(into))]
+                        pub $req_param: $req_type,
+                    )*
+                )?
+                $(
+                    $(
+                        #[builder(setter(into), default)]
+                        pub $opt_param: Option<$opt_type>,
+                    )*
+                )?
+            }
+
+            // Define the response struct
+            #[derive(Debug, serde::Serialize, serde::Deserialize)]
+            #[serde(rename_all = "camelCase")]
+            pub struct $response_type {
+                $(
+                    $(#[$field_meta])*
+                    pub $resp_field: $resp_type,
+                )*
+            }
+
+            impl $impl_target {
+                $(#[$meta])*
+                pub async fn $fn_name(
+                    &self,
+                    $(
+                        $path_param : $path_type,
+                    )*
+                    params: &[<$fn_name:camel Params>],
+                    files: Vec<apiclient_rs::FilePart>,
+                ) -> apiclient_rs::ApiResult<$response_type> {
+                    let endpoint = format!($endpoint_fmt, $($path_param),*);
+                    let form = apiclient_rs::MultipartRequest::from_serializable(params)?.files(files);
+                    self.[<$verb _multipart>](&endpoint, form).await
+                }
+            }
+        }
+    };
+
+    // When parameters are provided and the body should be sent as
+    // `application/x-www-form-urlencoded` rather than JSON (`method:
+    // put(form);`/`patch(form);`/`delete(form);` — see
+    // `create_api_submodule!`'s `put_form`/`patch_form`/`delete_form`).
+    (
+        $(#[$meta:meta])*
+        impl $impl_target:ty;
+        fn $fn_name:ident(
+            &self $(, $path_param:ident : $path_type:ty)* $(,)?
+            $(; required_params: {$($req_param:ident : $req_type:ty),* $(,)?})?
+            $(; optional_params: {$($opt_param:ident : $opt_type:ty),* $(,)?})?
+        ) -> $response_type:ident;
+        method: $method:ident(form);
+        endpoint: $endpoint_fmt:expr;
+        response_fields: {
+            $(
+                $(#[$field_meta:meta])*
+                $resp_field:ident : $resp_type:ty
+            ),* $(,)?
+        }
+    ) => {
+        paste::paste! {
+            // Define the parameter struct
+            #[derive(Debug, Clone, serde::Serialize, derive_builder::Builder)]
+            #[builder(public)]
+            #[serde(rename_all = "camelCase")]
+            pub struct [<$fn_name:camel Params>] {
+                $(
+                    $(
+                        #[builder(setter(into))]
+                        pub $req_param: $req_type,
+                    )*
+                )?
+                $(
+                    $(
+                        #[builder(setter(into), default)]
+                        pub $opt_param: Option<$opt_type>,
+                    )*
+                )?
+            }
+
+            // Define the response struct
+            #[derive(Debug, serde::Serialize, serde::Deserialize)]
+            #[serde(rename_all = "camelCase")]
+            pub struct $response_type {
+                $(
+                    $(#[$field_meta])*
+                    pub $resp_field: $resp_type,
+                )*
+            }
+
+            impl $impl_target {
+                $(#[$meta])*
+                pub async fn $fn_name(
+                    &self,
+                    $(
+                        $path_param : $path_type,
+                    )*
+                    params: &[<$fn_name:camel Params>],
+                ) -> apiclient_rs::ApiResult<$response_type> {
+                    let endpoint = format!($endpoint_fmt, $($path_param),*);
+                    self.[<$method _form>](&endpoint, Some(&params)).await
+                }
+            }
+        }
+    };
+
     // When parameters are provided
     (
         $(#[$meta:meta])*
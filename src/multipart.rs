@@ -0,0 +1,208 @@
+use std::fmt::{self, Debug};
+use std::pin::Pin;
+
+use mime_guess::Mime;
+use reqwest::multipart::{Form, Part};
+use serde::Serialize;
+use serde_json::Value;
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+use tracing::debug;
+
+use crate::error::ApiClientError;
+
+enum FileData {
+    Bytes(Vec<u8>),
+    Reader(Pin<Box<dyn AsyncRead + Send + Sync>>),
+}
+
+/// A single file part of a multipart request: a field name, filename, its
+/// bytes (or an async reader for large uploads), and an optional explicit
+/// `Content-Type`, otherwise guessed from the filename via `mime_guess`.
+pub struct FilePart {
+    field_name: String,
+    filename: String,
+    data: FileData,
+    content_type: Option<String>,
+}
+
+impl FilePart {
+    pub fn bytes(
+        field_name: impl Into<String>,
+        filename: impl Into<String>,
+        bytes: impl Into<Vec<u8>>,
+    ) -> Self {
+        let filename = filename.into();
+        let content_type = guess_content_type(&filename);
+        FilePart {
+            field_name: field_name.into(),
+            filename,
+            data: FileData::Bytes(bytes.into()),
+            content_type,
+        }
+    }
+
+    pub fn reader<R>(field_name: impl Into<String>, filename: impl Into<String>, reader: R) -> Self
+    where
+        R: AsyncRead + Send + Sync + 'static,
+    {
+        let filename = filename.into();
+        let content_type = guess_content_type(&filename);
+        FilePart {
+            field_name: field_name.into(),
+            filename,
+            data: FileData::Reader(Box::pin(reader)),
+            content_type,
+        }
+    }
+
+    pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+}
+
+impl Debug for FilePart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FilePart")
+            .field("field_name", &self.field_name)
+            .field("filename", &self.filename)
+            .field("content_type", &self.content_type)
+            .finish()
+    }
+}
+
+fn guess_content_type(filename: &str) -> Option<String> {
+    mime_guess::from_path(filename)
+        .first()
+        .map(|mime| mime.essence_str().to_string())
+}
+
+/// Builder for a `multipart/form-data` request: text fields plus file
+/// parts, consumed by `ApiClient::post_multipart`/`put_multipart`.
+#[derive(Default)]
+pub struct MultipartRequest {
+    fields: Vec<(String, String)>,
+    files: Vec<FilePart>,
+}
+
+impl MultipartRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the text fields from a `Serialize` struct, the same way
+    /// `ApiClient::serialize_params` flattens query params.
+    pub fn from_serializable<B: Serialize>(value: &B) -> Result<Self, ApiClientError> {
+        let json = serde_json::to_value(value)?;
+        let mut form = Self::new();
+
+        if let Value::Object(map) = json {
+            for (key, value) in map {
+                if value.is_null() {
+                    continue;
+                }
+                let value_str = match value {
+                    Value::String(s) => s,
+                    Value::Bool(b) => b.to_string(),
+                    Value::Number(n) => n.to_string(),
+                    other => other.to_string(),
+                };
+                form = form.text(key, value_str);
+            }
+        }
+
+        Ok(form)
+    }
+
+    pub fn text(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.fields.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn file(mut self, part: FilePart) -> Self {
+        self.files.push(part);
+        self
+    }
+
+    pub fn files(mut self, parts: impl IntoIterator<Item = FilePart>) -> Self {
+        self.files.extend(parts);
+        self
+    }
+
+    pub(crate) fn into_form(self) -> Form {
+        let mut form = Form::new();
+
+        for (name, value) in self.fields {
+            form = form.text(name, value);
+        }
+
+        for file in self.files {
+            let mut part = match file.data {
+                FileData::Bytes(bytes) => Part::bytes(bytes),
+                FileData::Reader(reader) => {
+                    Part::stream(reqwest::Body::wrap_stream(ReaderStream::new(reader)))
+                }
+            }
+            .file_name(file.filename);
+
+            if let Some(content_type) = file.content_type {
+                // Parse before touching `part`: `Part::mime_str` consumes
+                // `self` and drops it on a parse error, which would leave
+                // nothing to fall back to below.
+                match content_type.parse::<Mime>() {
+                    Ok(mime) => part = part.mime(mime),
+                    Err(e) => debug!("Invalid content type {:?} for multipart file part: {}", content_type, e),
+                }
+            }
+
+            form = form.part(file.field_name, part);
+        }
+
+        form
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Params {
+        name: String,
+        tags: Vec<String>,
+        note: Option<String>,
+    }
+
+    #[test]
+    fn from_serializable_preserves_array_fields_instead_of_dropping_them() {
+        let params = Params {
+            name: "example".to_string(),
+            tags: vec!["a".to_string(), "b".to_string()],
+            note: None,
+        };
+
+        let form = MultipartRequest::from_serializable(&params).unwrap();
+
+        assert!(form.fields.contains(&("name".to_string(), "example".to_string())));
+        // Arrays used to be silently dropped; now they're kept (as their
+        // JSON representation), matching `ApiClient::serialize_params`.
+        assert!(form.fields.iter().any(|(k, v)| k == "tags" && v.contains('a') && v.contains('b')));
+        // `None` fields are still skipped entirely.
+        assert!(!form.fields.iter().any(|(k, _)| k == "note"));
+    }
+
+    #[test]
+    fn text_and_file_accumulate_in_order() {
+        let form = MultipartRequest::new()
+            .text("a", "1")
+            .text("b", "2")
+            .file(FilePart::bytes("upload", "report.txt", b"hello".to_vec()));
+
+        assert_eq!(
+            form.fields,
+            vec![("a".to_string(), "1".to_string()), ("b".to_string(), "2".to_string())]
+        );
+        assert_eq!(form.files.len(), 1);
+    }
+}
@@ -0,0 +1,199 @@
+use async_stream::try_stream;
+use futures_core::Stream;
+use reqwest::header::HeaderMap;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::client::{ApiClient, ApiResult};
+use crate::error::ApiClientError;
+use crate::ratelimit::route_key;
+
+/// Bounds on how far `ApiClient::get_paginated` traverses a collection.
+#[derive(Debug, Clone, Default)]
+pub struct PageOpts {
+    /// Page size, sent as a `limit` query parameter.
+    pub limit: Option<u32>,
+    /// Stop after this many pages even if more remain.
+    pub max_pages: Option<u32>,
+    /// Cursor to start from, sent as a `cursor` query parameter on the
+    /// first request.
+    pub start_cursor: Option<String>,
+}
+
+/// Dot-path to a JSON field holding the next page's cursor (e.g.
+/// `"meta.next"`), consulted when the response carries no `Link` header.
+#[derive(Debug, Clone)]
+pub struct CursorField(pub String);
+
+impl From<&str> for CursorField {
+    fn from(path: &str) -> Self {
+        CursorField(path.to_string())
+    }
+}
+
+enum NextPage {
+    /// A fully-qualified URL from a `Link: rel="next"` header.
+    Url(String),
+    /// A cursor value read from `cursor_field`, sent as `?cursor=`.
+    Cursor(String),
+}
+
+impl ApiClient {
+    /// Lazily fetches every page of `endpoint`, following the `Link:
+    /// rel="next"` response header (or, if `cursor_field` is given, a JSON
+    /// cursor field) until exhausted or `opts.max_pages` is reached.
+    pub fn get_paginated<T>(
+        &self,
+        endpoint: &str,
+        cursor_field: Option<CursorField>,
+        opts: PageOpts,
+    ) -> impl Stream<Item = ApiResult<T>> + '_
+    where
+        T: DeserializeOwned + 'static,
+    {
+        let endpoint = endpoint.to_string();
+        let route = route_key(&endpoint);
+
+        try_stream! {
+            let mut next_cursor = opts.start_cursor.clone();
+            let mut next_url: Option<String> = None;
+            let mut pages_fetched = 0u32;
+
+            loop {
+                if let Some(max_pages) = opts.max_pages {
+                    if pages_fetched >= max_pages {
+                        break;
+                    }
+                }
+
+                let (value, headers) = if let Some(url) = next_url.take() {
+                    self.get_page_absolute(&url, &route).await?
+                } else {
+                    let params = page_params(opts.limit, next_cursor.as_deref());
+                    self.get_page(&endpoint, Some(&params)).await?
+                };
+
+                pages_fetched += 1;
+
+                let page: T = serde_json::from_value(value.clone()).map_err(ApiClientError::JsonParse)?;
+                yield page;
+
+                match next_page(&headers, &value, cursor_field.as_ref()) {
+                    Some(NextPage::Url(url)) => next_url = Some(url),
+                    Some(NextPage::Cursor(cursor)) => next_cursor = Some(cursor),
+                    None => break,
+                }
+            }
+        }
+    }
+}
+
+fn page_params(limit: Option<u32>, cursor: Option<&str>) -> Vec<(String, String)> {
+    let mut params = Vec::new();
+    if let Some(limit) = limit {
+        params.push(("limit".to_string(), limit.to_string()));
+    }
+    if let Some(cursor) = cursor {
+        params.push(("cursor".to_string(), cursor.to_string()));
+    }
+    params
+}
+
+fn next_page(headers: &HeaderMap, body: &Value, cursor_field: Option<&CursorField>) -> Option<NextPage> {
+    if let Some(link) = headers.get(reqwest::header::LINK).and_then(|v| v.to_str().ok()) {
+        if let Some(url) = parse_link_next(link) {
+            return Some(NextPage::Url(url));
+        }
+    }
+
+    let cursor_field = cursor_field?;
+    let mut current = body;
+    for segment in cursor_field.0.split('.') {
+        current = current.get(segment)?;
+    }
+
+    match current {
+        Value::String(s) if !s.is_empty() => Some(NextPage::Cursor(s.clone())),
+        Value::Number(n) => Some(NextPage::Cursor(n.to_string())),
+        _ => None,
+    }
+}
+
+/// Parses an RFC 5988 `Link` header for the `rel="next"` target URL.
+fn parse_link_next(link_header: &str) -> Option<String> {
+    for entry in link_header.split(',') {
+        let mut segments = entry.split(';');
+        let url = segments.next()?.trim().strip_prefix('<')?.strip_suffix('>')?;
+
+        let is_next = segments.any(|segment| {
+            segment
+                .trim()
+                .strip_prefix("rel=")
+                .map(|rel| rel.trim_matches('"') == "next")
+                .unwrap_or(false)
+        });
+
+        if is_next {
+            return Some(url.to_string());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parse_link_next_extracts_rel_next_url() {
+        let header = r#"<https://api.example.com/items?page=2>; rel="next", <https://api.example.com/items?page=1>; rel="prev""#;
+        assert_eq!(
+            parse_link_next(header),
+            Some("https://api.example.com/items?page=2".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_link_next_returns_none_without_a_next_rel() {
+        let header = r#"<https://api.example.com/items?page=1>; rel="prev""#;
+        assert_eq!(parse_link_next(header), None);
+    }
+
+    #[test]
+    fn next_page_prefers_link_header_over_cursor_field() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            reqwest::header::LINK,
+            r#"<https://api.example.com/items?page=2>; rel="next""#.parse().unwrap(),
+        );
+        let body = json!({"meta": {"next": "abc"}});
+        let cursor_field = CursorField::from("meta.next");
+
+        match next_page(&headers, &body, Some(&cursor_field)) {
+            Some(NextPage::Url(url)) => assert_eq!(url, "https://api.example.com/items?page=2"),
+            other => panic!("expected NextPage::Url, got is_some={}", other.is_some()),
+        }
+    }
+
+    #[test]
+    fn next_page_falls_back_to_cursor_field_without_a_link_header() {
+        let headers = HeaderMap::new();
+        let body = json!({"meta": {"next": "abc123"}});
+        let cursor_field = CursorField::from("meta.next");
+
+        match next_page(&headers, &body, Some(&cursor_field)) {
+            Some(NextPage::Cursor(cursor)) => assert_eq!(cursor, "abc123"),
+            other => panic!("expected NextPage::Cursor, got is_some={}", other.is_some()),
+        }
+    }
+
+    #[test]
+    fn next_page_returns_none_when_exhausted() {
+        let headers = HeaderMap::new();
+        let body = json!({"meta": {}});
+        let cursor_field = CursorField::from("meta.next");
+
+        assert!(next_page(&headers, &body, Some(&cursor_field)).is_none());
+    }
+}
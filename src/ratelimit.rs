@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::HeaderMap;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+use tracing::debug;
+
+/// Per-route rate-limit state, derived from `X-RateLimit-*` response headers.
+#[derive(Debug, Clone, Default)]
+struct Bucket {
+    limit: Option<u32>,
+    remaining: Option<u32>,
+    reset_at: Option<Instant>,
+}
+
+/// Tracks one [`Bucket`] per route identifier (e.g. `/users/{id}`), shared
+/// across clones of `ApiClient` so concurrent requests see the same limits.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RateLimiter {
+    buckets: Arc<Mutex<HashMap<String, Bucket>>>,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Awaits until `route` is known to have budget again, if a previous
+    /// response reported it as exhausted.
+    pub async fn wait_for_capacity(&self, route: &str) {
+        let reset_at = {
+            let buckets = self.buckets.lock().await;
+            buckets.get(route).and_then(|bucket| match bucket.remaining {
+                Some(0) => bucket.reset_at,
+                _ => None,
+            })
+        };
+
+        if let Some(reset_at) = reset_at {
+            let now = Instant::now();
+            if reset_at > now {
+                debug!("Route {} exhausted, waiting {:?} for reset", route, reset_at - now);
+                sleep(reset_at - now).await;
+            }
+        }
+    }
+
+    /// Updates the bucket for `route` from a response's rate-limit headers.
+    pub async fn observe(&self, route: &str, headers: &HeaderMap) {
+        let limit = header_u32(headers, "x-ratelimit-limit");
+        let remaining = header_u32(headers, "x-ratelimit-remaining");
+        let reset_at = header_u64(headers, "x-ratelimit-reset").map(epoch_or_delta_to_instant);
+
+        if limit.is_none() && remaining.is_none() && reset_at.is_none() {
+            return;
+        }
+
+        let mut buckets = self.buckets.lock().await;
+        let bucket = buckets.entry(route.to_string()).or_default();
+        if let Some(limit) = limit {
+            bucket.limit = Some(limit);
+        }
+        if let Some(remaining) = remaining {
+            bucket.remaining = Some(remaining);
+        }
+        if let Some(reset_at) = reset_at {
+            bucket.reset_at = Some(reset_at);
+        }
+    }
+}
+
+fn header_u32(headers: &HeaderMap, name: &str) -> Option<u32> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// `X-RateLimit-Reset` is sent as either a delta in seconds or an absolute
+/// Unix timestamp depending on the backend; values greater than the current
+/// Unix time are treated as absolute, everything else (including a Unix
+/// timestamp that has already elapsed) as a delta.
+fn epoch_or_delta_to_instant(value: u64) -> Instant {
+    let now_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    if value > now_epoch {
+        Instant::now() + Duration::from_secs(value - now_epoch)
+    } else {
+        Instant::now() + Duration::from_secs(value)
+    }
+}
+
+/// Parses a `Retry-After` header value, which per RFC 9110 is either a
+/// number of seconds or an HTTP-date.
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let at = httpdate::parse_http_date(value).ok()?;
+    at.duration_since(SystemTime::now()).ok()
+}
+
+/// Collapses concrete path segments (numeric ids, UUIDs) down to a stable
+/// route identifier, so `/users/42` and `/users/43` share a bucket.
+pub(crate) fn route_key(endpoint: &str) -> String {
+    endpoint
+        .split('/')
+        .map(|segment| {
+            if is_numeric_id(segment) || is_uuid(segment) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn is_numeric_id(segment: &str) -> bool {
+    !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_uuid(segment: &str) -> bool {
+    let stripped: String = segment.chars().filter(|c| *c != '-').collect();
+    stripped.len() == 32 && stripped.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn route_key_collapses_numeric_and_uuid_segments() {
+        assert_eq!(route_key("/users/42"), "/users/{id}");
+        assert_eq!(route_key("/users/42/posts/7"), "/users/{id}/posts/{id}");
+        assert_eq!(
+            route_key("/items/550e8400-e29b-41d4-a716-446655440000"),
+            "/items/{id}"
+        );
+        assert_eq!(route_key("/users"), "/users");
+    }
+
+    #[test]
+    fn parse_retry_after_accepts_seconds() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn parse_retry_after_rejects_garbage() {
+        assert_eq!(parse_retry_after("not-a-duration"), None);
+    }
+
+    #[test]
+    fn parse_retry_after_rejects_a_date_already_in_the_past() {
+        assert_eq!(parse_retry_after("Mon, 01 Jan 1990 00:00:00 GMT"), None);
+    }
+
+    #[test]
+    fn epoch_or_delta_treats_small_values_as_a_delta() {
+        let before = Instant::now();
+        let resolved = epoch_or_delta_to_instant(5);
+        assert!(resolved >= before + Duration::from_secs(4));
+        assert!(resolved <= before + Duration::from_secs(6));
+    }
+
+    #[test]
+    fn epoch_or_delta_treats_future_unix_timestamp_as_absolute() {
+        let now_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let before = Instant::now();
+        let resolved = epoch_or_delta_to_instant(now_epoch + 10);
+        assert!(resolved >= before + Duration::from_secs(9));
+        assert!(resolved <= before + Duration::from_secs(11));
+    }
+}